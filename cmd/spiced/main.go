@@ -5,8 +5,10 @@ import (
 	"log"
 	"os"
 	"os/signal"
+	"strings"
 	"syscall"
 
+	"github.com/logrusorgru/aurora"
 	"github.com/spf13/cobra"
 	"github.com/spiceai/spiceai/pkg/context"
 	"github.com/spiceai/spiceai/pkg/loggers"
@@ -15,7 +17,8 @@ import (
 )
 
 var (
-	contextFlag string
+	contextFlag  string
+	validateFlag bool
 )
 
 func main() {
@@ -57,6 +60,18 @@ var RootCmd = &cobra.Command{
 			manifestPath = args[0]
 		}
 
+		if validateFlag {
+			results, err := runtime.Validate(manifestPath)
+			if err != nil {
+				fmt.Println(err)
+				os.Exit(1)
+			}
+			if !printValidationResults(results) {
+				os.Exit(1)
+			}
+			return
+		}
+
 		isSingleRun := manifestPath != ""
 
 		if isSingleRun {
@@ -77,6 +92,26 @@ var RootCmd = &cobra.Command{
 	},
 }
 
+// Prints each manifest's validation result and returns whether all are valid
+func printValidationResults(results []runtime.ValidationResult) bool {
+	numInvalid := 0
+	for _, result := range results {
+		if result.Err != nil {
+			numInvalid++
+			fmt.Printf("%s %s: %s\n", aurora.Red("invalid"), result.ManifestPath, strings.TrimSpace(result.Err.Error()))
+			continue
+		}
+		fmt.Printf("%s %s\n", aurora.Green("valid"), result.ManifestPath)
+	}
+
+	if numInvalid > 0 {
+		fmt.Printf("%d of %d pod manifests are invalid\n", numInvalid, len(results))
+		return false
+	}
+
+	return true
+}
+
 var VersionCmd = &cobra.Command{
 	Use:   "version",
 	Short: "Version information",
@@ -87,5 +122,6 @@ var VersionCmd = &cobra.Command{
 
 func init() {
 	RootCmd.Flags().StringVar(&contextFlag, "context", "metal", "Runs Spice.ai in the given context, either 'docker' or 'metal'")
+	RootCmd.Flags().BoolVar(&validateFlag, "validate", false, "Validates the given pod manifest, or all pods in the app, without starting the runtime")
 	RootCmd.AddCommand(VersionCmd)
 }
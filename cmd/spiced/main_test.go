@@ -0,0 +1,26 @@
+package main
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/spiceai/spiceai/pkg/runtime"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestPrintValidationResults(t *testing.T) {
+	t.Run("printValidationResults() - all valid", testPrintValidationResultsFunc([]runtime.ValidationResult{
+		{ManifestPath: "spicepods/a.yaml"},
+		{ManifestPath: "spicepods/b.yaml"},
+	}, true))
+	t.Run("printValidationResults() - one invalid", testPrintValidationResultsFunc([]runtime.ValidationResult{
+		{ManifestPath: "spicepods/a.yaml"},
+		{ManifestPath: "spicepods/b.yaml", Err: errors.New("interval must be less than or equal to period")},
+	}, false))
+}
+
+func testPrintValidationResultsFunc(results []runtime.ValidationResult, expected bool) func(t *testing.T) {
+	return func(t *testing.T) {
+		assert.Equal(t, expected, printValidationResults(results))
+	}
+}
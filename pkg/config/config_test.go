@@ -8,6 +8,7 @@ import (
 	"reflect"
 	"strconv"
 	"testing"
+	"time"
 
 	"github.com/spf13/viper"
 	"github.com/spiceai/spiceai/pkg/config"
@@ -23,6 +24,7 @@ func TestConfig(t *testing.T) {
 	t.Run("LoadRuntimeConfiguration() - Config loads correctly", testRuntimeConfigLoads(testConfigPath))
 	testutils.CleanupTestSpiceDirectory()
 	t.Run("LoadRuntimeConfiguration() - Environment variables in config are replaced", testRuntimeConfigReplacesEnvironmentVariables(testConfigPathWithEnvVars))
+	t.Run("GetShutdownGracePeriod() - Defaults when unset or zero", testShutdownGracePeriodDefaults())
 }
 
 // Tests configuration loads correctly
@@ -48,6 +50,9 @@ func testRuntimeConfigLoads(testConfigPath string) func(*testing.T) {
 		if !reflect.DeepEqual(expected, actual) {
 			t.Errorf("Expected:\n%v\nGot:\n%v", expected, actual)
 		}
+
+		assert.True(t, spiceConfiguration.HttpCompression)
+		assert.Equal(t, 10*time.Second, spiceConfiguration.GetShutdownGracePeriod())
 	}
 }
 
@@ -79,6 +84,23 @@ func testRuntimeConfigReplacesEnvironmentVariables(testConfigPath string) func(*
 
 		actual := spiceConfiguration.HttpPort
 		assert.Equal(t, expected, actual, "Expected:\n%d\nGot:\n%d", expected, actual)
+
+		// http_compression and shutdown_grace_period are not set in this config
+		assert.False(t, spiceConfiguration.HttpCompression)
+		assert.Equal(t, config.DefaultShutdownGracePeriod, spiceConfiguration.GetShutdownGracePeriod())
+	}
+}
+
+// Tests the shutdown grace period falls back to the default
+func testShutdownGracePeriodDefaults() func(*testing.T) {
+	return func(t *testing.T) {
+		assert.Equal(t, config.DefaultShutdownGracePeriod, config.LoadDefaultConfiguration().GetShutdownGracePeriod())
+
+		spiceConfiguration := &config.SpiceConfiguration{ShutdownGracePeriod: 0}
+		assert.Equal(t, config.DefaultShutdownGracePeriod, spiceConfiguration.GetShutdownGracePeriod())
+
+		spiceConfiguration = &config.SpiceConfiguration{ShutdownGracePeriod: 5 * time.Second}
+		assert.Equal(t, 5*time.Second, spiceConfiguration.GetShutdownGracePeriod())
 	}
 }
 
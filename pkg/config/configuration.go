@@ -4,6 +4,7 @@ import (
 	"bytes"
 	"fmt"
 	"os"
+	"time"
 
 	"github.com/spf13/viper"
 	"github.com/spiceai/spiceai/pkg/constants"
@@ -11,8 +12,12 @@ import (
 	"gopkg.in/yaml.v2"
 )
 
+const DefaultShutdownGracePeriod = 20 * time.Second
+
 type SpiceConfiguration struct {
-	HttpPort uint `json:"http_port,omitempty" mapstructure:"http_port,omitempty" yaml:"http_port,omitempty"`
+	HttpPort            uint          `json:"http_port,omitempty" mapstructure:"http_port,omitempty" yaml:"http_port,omitempty"`
+	HttpCompression     bool          `json:"http_compression,omitempty" mapstructure:"http_compression,omitempty" yaml:"http_compression,omitempty"`
+	ShutdownGracePeriod time.Duration `json:"shutdown_grace_period,omitempty" mapstructure:"shutdown_grace_period,omitempty" yaml:"shutdown_grace_period,omitempty"`
 }
 
 func LoadDefaultConfiguration() *SpiceConfiguration {
@@ -60,6 +65,14 @@ func (rtConfig *SpiceConfiguration) ServerBaseUrl() string {
 	return fmt.Sprintf("http://localhost:%d", rtConfig.HttpPort)
 }
 
+// Maximum time to wait for in-flight requests to complete on shutdown
+func (rtConfig *SpiceConfiguration) GetShutdownGracePeriod() time.Duration {
+	if rtConfig.ShutdownGracePeriod <= 0 {
+		return DefaultShutdownGracePeriod
+	}
+	return rtConfig.ShutdownGracePeriod
+}
+
 func (rtConfig *SpiceConfiguration) WriteToFile() error {
 	configPath := fmt.Sprintf("%s.yaml", constants.SpiceConfigBaseName)
 	configFile, err := os.Create(configPath)
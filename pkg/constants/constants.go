@@ -6,6 +6,7 @@ const (
 	SpicePodsDirectoryName = "spicepods"
 	SpiceRuntimeFilename   = "spiced"
 	SpicePodFileExtension  = ".spicepod"
+	SpicePodOverrideSuffix = ".override"
 	PythonCmd              = "python3"
 	SpiceEnvVarPrefix      = "SPICE_"
 )
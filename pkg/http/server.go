@@ -4,6 +4,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"log"
+	"net"
 	"net/http"
 	"strings"
 	"time"
@@ -24,33 +25,57 @@ import (
 )
 
 type ServerConfig struct {
-	Port uint
+	Port        uint
+	Compression bool
 }
 
 type server struct {
-	config ServerConfig
+	config     ServerConfig
+	fastServer *fasthttp.Server
 }
 
 var (
 	zaplog *zap.Logger = loggers.ZapLogger()
+
+	aiEngineReady   = aiengine.ServerReady
+	aiEngineHealthy = aiengine.IsServerHealthy
 )
 
-func healthHandler(ctx *fasthttp.RequestCtx) {
-	if !aiengine.ServerReady() {
-		fmt.Fprintf(ctx, "initializing")
-		return
+// Returns "ok" and true when the AI engine is ready and healthy,
+// otherwise a description of why it is not and false.
+func runtimeHealth() (string, bool) {
+	if !aiEngineReady() {
+		return "initializing", false
 	}
 
-	err := aiengine.IsServerHealthy()
+	err := aiEngineHealthy()
 	if err != nil {
-		fmt.Fprintf(ctx, "degraded\n")
-		fmt.Fprintf(ctx, "ai: %s", err.Error())
-		return
+		return fmt.Sprintf("degraded\nai: %s", err.Error()), false
 	}
 
+	return "ok", true
+}
+
+func healthHandler(ctx *fasthttp.RequestCtx) {
+	status, _ := runtimeHealth()
+	fmt.Fprint(ctx, status)
+}
+
+// Liveness only reflects that the HTTP server loop is serving requests,
+// so orchestrators don't restart the runtime while the AI engine initializes.
+func healthLiveHandler(ctx *fasthttp.RequestCtx) {
 	fmt.Fprintf(ctx, "ok")
 }
 
+// Readiness reflects whether the runtime can serve pod requests.
+func healthReadyHandler(ctx *fasthttp.RequestCtx) {
+	status, ready := runtimeHealth()
+	if !ready {
+		ctx.Response.SetStatusCode(http.StatusServiceUnavailable)
+	}
+	fmt.Fprint(ctx, status)
+}
+
 func apiGetObservationsHandler(ctx *fasthttp.RequestCtx) {
 	podParam := ctx.UserValue("pod").(string)
 	pod := pods.GetPod(podParam)
@@ -444,10 +469,11 @@ func apiPostImportHandler(ctx *fasthttp.RequestCtx) {
 	ctx.Response.SetStatusCode(200)
 }
 
-func NewServer(port uint) *server {
+func NewServer(port uint, compression bool) *server {
 	return &server{
 		config: ServerConfig{
-			Port: port,
+			Port:        port,
+			Compression: compression,
 		},
 	}
 }
@@ -455,10 +481,11 @@ func NewServer(port uint) *server {
 func (server *server) Start() error {
 	r := router.New()
 	r.GET("/health", healthHandler)
+	r.GET("/health/live", healthLiveHandler)
+	r.GET("/health/ready", healthReadyHandler)
 
 	// Static Dashboard
 	dashboardServer := dashboard.NewDashboardEmbedded()
-	var err error
 
 	api := r.Group("/api/v0.1")
 	{
@@ -502,18 +529,62 @@ func (server *server) Start() error {
 	})
 	r.GET("/", dashboardServer.IndexHandler)
 
+	handler := compressHandler(r.Handler, server.config.Compression)
+
+	ln, err := net.Listen("tcp4", fmt.Sprintf(":%d", server.config.Port))
+	if err != nil {
+		return err
+	}
+
+	return server.serve(ln, handler)
+}
+
+// When enabled, responses are compressed for clients that send a matching Accept-Encoding
+func compressHandler(handler fasthttp.RequestHandler, enabled bool) fasthttp.RequestHandler {
+	if !enabled {
+		return handler
+	}
+	return fasthttp.CompressHandlerBrotliLevel(handler, fasthttp.CompressBrotliDefaultCompression, fasthttp.CompressDefaultCompression)
+}
+
+// Serves handler on ln in the background until Shutdown is called
+func (server *server) serve(ln net.Listener, handler fasthttp.RequestHandler) error {
 	serverLogger, err := zap.NewStdLogAt(zaplog, zap.DebugLevel)
 	if err != nil {
 		return fmt.Errorf("failed to initialize logger: %w", err)
 	}
-	fastServer := &fasthttp.Server{
-		Handler: r.Handler,
+
+	server.fastServer = &fasthttp.Server{
+		Handler: handler,
 		Logger:  serverLogger,
 	}
 
 	go func() {
-		log.Fatal(fastServer.ListenAndServe(fmt.Sprintf(":%d", server.config.Port)))
+		err := server.fastServer.Serve(ln)
+		if err != nil {
+			log.Fatal(err)
+		}
 	}()
 
 	return nil
 }
+
+// Shutdown stops accepting new connections and waits up to gracePeriod
+// for in-flight requests to complete.
+func (server *server) Shutdown(gracePeriod time.Duration) error {
+	if server.fastServer == nil {
+		return nil
+	}
+
+	done := make(chan error, 1)
+	go func() {
+		done <- server.fastServer.Shutdown()
+	}()
+
+	select {
+	case err := <-done:
+		return err
+	case <-time.After(gracePeriod):
+		return fmt.Errorf("in-flight requests did not complete within %s", gracePeriod)
+	}
+}
@@ -2,8 +2,14 @@ package http
 
 import (
 	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"strings"
 	"testing"
+	"time"
 
+	"github.com/spiceai/spiceai/pkg/aiengine"
 	"github.com/spiceai/spiceai/pkg/api"
 	"github.com/spiceai/spiceai/pkg/interpretations"
 	"github.com/spiceai/spiceai/pkg/pods"
@@ -22,6 +28,14 @@ func TestServer(t *testing.T) {
 
 	t.Run("getInterpretations()", testGetInterpretationsHandlerFunc(pod))
 	t.Run("postInterpretations()", testPostInterpretationsHandlerFunc(pod))
+	t.Run("healthLive()", testHealthLiveHandlerFunc())
+	t.Run("healthReady()", testHealthReadyHandlerFunc())
+	t.Run("compressHandler()", testCompressHandlerFunc())
+}
+
+func TestServerShutdown(t *testing.T) {
+	t.Run("Shutdown() - in-flight request completes within grace period", testShutdownFunc(true))
+	t.Run("Shutdown() - in-flight request exceeds grace period", testShutdownFunc(false))
 }
 
 func testGetInterpretationsHandlerFunc(pod *pods.Pod) func(t *testing.T) {
@@ -85,3 +99,167 @@ func testPostInterpretationsHandlerFunc(pod *pods.Pod) func(t *testing.T) {
 		assert.Equal(t, interpretation, &interpretations[0])
 	}
 }
+
+func testHealthLiveHandlerFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		ctx := &fasthttp.RequestCtx{
+			Request: fasthttp.Request{},
+		}
+
+		healthLiveHandler(ctx)
+
+		assert.Equal(t, 200, ctx.Response.StatusCode())
+		assert.Equal(t, "ok", string(ctx.Response.Body()))
+	}
+}
+
+func testHealthReadyHandlerFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		t.Cleanup(func() {
+			aiEngineReady = aiengine.ServerReady
+			aiEngineHealthy = aiengine.IsServerHealthy
+		})
+
+		testCases := []struct {
+			name           string
+			ready          bool
+			healthErr      error
+			expectedStatus int
+			expectedBody   string
+		}{
+			{"initializing", false, nil, 503, "initializing"},
+			{"degraded", true, errors.New("unavailable"), 503, "degraded\nai: unavailable"},
+			{"ok", true, nil, 200, "ok"},
+		}
+
+		for _, tc := range testCases {
+			ready := tc.ready
+			healthErr := tc.healthErr
+			aiEngineReady = func() bool { return ready }
+			aiEngineHealthy = func() error { return healthErr }
+
+			ctx := &fasthttp.RequestCtx{
+				Request: fasthttp.Request{},
+			}
+			healthReadyHandler(ctx)
+
+			assert.Equal(t, tc.expectedStatus, ctx.Response.StatusCode(), tc.name)
+			assert.Equal(t, tc.expectedBody, string(ctx.Response.Body()), tc.name)
+
+			ctx = &fasthttp.RequestCtx{
+				Request: fasthttp.Request{},
+			}
+			healthHandler(ctx)
+
+			// /health always returns 200 and is only used for its body
+			assert.Equal(t, 200, ctx.Response.StatusCode(), tc.name)
+			assert.Equal(t, tc.expectedBody, string(ctx.Response.Body()), tc.name)
+		}
+	}
+}
+
+func testShutdownFunc(completesWithinGracePeriod bool) func(t *testing.T) {
+	return func(t *testing.T) {
+		inFlight := make(chan struct{})
+		release := make(chan struct{})
+		handler := func(ctx *fasthttp.RequestCtx) {
+			close(inFlight)
+			<-release
+			fmt.Fprintf(ctx, "ok")
+		}
+
+		ln, err := net.Listen("tcp4", "127.0.0.1:0")
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		testServer := NewServer(0, false)
+		err = testServer.serve(ln, handler)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		responseErr := make(chan error, 1)
+		go func() {
+			req := fasthttp.AcquireRequest()
+			defer fasthttp.ReleaseRequest(req)
+			resp := fasthttp.AcquireResponse()
+			defer fasthttp.ReleaseResponse(resp)
+
+			req.SetRequestURI(fmt.Sprintf("http://%s/", ln.Addr().String()))
+			req.SetConnectionClose()
+			responseErr <- fasthttp.Do(req, resp)
+		}()
+
+		<-inFlight
+
+		if completesWithinGracePeriod {
+			go func() {
+				time.Sleep(100 * time.Millisecond)
+				close(release)
+			}()
+
+			err = testServer.Shutdown(5 * time.Second)
+			assert.NoError(t, err)
+		} else {
+			err = testServer.Shutdown(100 * time.Millisecond)
+			close(release)
+
+			if assert.Error(t, err) {
+				assert.Contains(t, err.Error(), "did not complete within")
+			}
+		}
+
+		// The in-flight request is never interrupted
+		assert.NoError(t, <-responseErr)
+	}
+}
+
+func testCompressHandlerFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		// Bodies must be large enough for fasthttp to compress them
+		expectedBody := strings.Repeat("spice ", 200)
+		handler := func(ctx *fasthttp.RequestCtx) {
+			ctx.Response.Header.SetContentType("text/plain")
+			fmt.Fprint(ctx, expectedBody)
+		}
+
+		testCases := []struct {
+			name             string
+			enabled          bool
+			acceptEncoding   string
+			expectedEncoding string
+		}{
+			{"brotli", true, "br", "br"},
+			{"gzip", true, "gzip", "gzip"},
+			{"no Accept-Encoding", true, "", ""},
+			{"disabled", false, "gzip", ""},
+		}
+
+		for _, tc := range testCases {
+			ctx := &fasthttp.RequestCtx{
+				Request: fasthttp.Request{},
+			}
+			if tc.acceptEncoding != "" {
+				ctx.Request.Header.Set("Accept-Encoding", tc.acceptEncoding)
+			}
+
+			compressHandler(handler, tc.enabled)(ctx)
+
+			assert.Equal(t, tc.expectedEncoding, string(ctx.Response.Header.Peek("Content-Encoding")), tc.name)
+
+			var body []byte
+			var err error
+			switch tc.expectedEncoding {
+			case "br":
+				body, err = ctx.Response.BodyUnbrotli()
+			case "gzip":
+				body, err = ctx.Response.BodyGunzip()
+			default:
+				body = ctx.Response.Body()
+			}
+			assert.NoError(t, err, tc.name)
+			assert.Equal(t, expectedBody, string(body), tc.name)
+		}
+	}
+}
@@ -4,6 +4,7 @@ import (
 	"bytes"
 	"errors"
 	"fmt"
+	"os"
 	"path/filepath"
 	"regexp"
 	"sort"
@@ -428,8 +429,38 @@ func (pod *Pod) FetchNewData() ([]*state.State, error) {
 	return allState, nil
 }
 
-func unmarshalPod(podPath string) (*Pod, error) {
-	podBytes, err := util.ReplaceEnvVariablesFromPath(podPath, constants.SpiceEnvVarPrefix)
+var knownParams = []string{"epoch_time", "episodes", "granularity", "interval", "period"}
+
+// Reads the pod manifest at podPath, deep-merged with its override manifest if one exists
+func readPodConfig(podPath string) (*viper.Viper, error) {
+	v, err := readYamlConfig(podPath)
+	if err != nil {
+		return nil, err
+	}
+
+	overridePath := OverrideManifestPath(podPath)
+	if _, err := os.Stat(overridePath); err != nil {
+		// No override manifest
+		return v, nil
+	}
+
+	override, err := readYamlConfig(overridePath)
+	if err != nil {
+		return nil, fmt.Errorf("error loading override manifest %s: %w", overridePath, err)
+	}
+
+	merged := viper.New()
+	merged.SetConfigType("yaml")
+	err = merged.MergeConfigMap(mergePodConfig(v.AllSettings(), override.AllSettings()))
+	if err != nil {
+		return nil, fmt.Errorf("error merging override manifest %s: %w", overridePath, err)
+	}
+
+	return merged, nil
+}
+
+func readYamlConfig(path string) (*viper.Viper, error) {
+	configBytes, err := util.ReplaceEnvVariablesFromPath(path, constants.SpiceEnvVarPrefix)
 	if err != nil {
 		return nil, err
 	}
@@ -437,7 +468,129 @@ func unmarshalPod(podPath string) (*Pod, error) {
 	v := viper.New()
 	v.SetConfigType("yaml")
 
-	err = v.ReadConfig(bytes.NewBuffer(podBytes))
+	err = v.ReadConfig(bytes.NewBuffer(configBytes))
+	if err != nil {
+		return nil, err
+	}
+
+	return v, nil
+}
+
+// Deep-merges an override manifest into a pod manifest. Maps are merged key by key,
+// dataspaces are matched by from and name, and any other value in override replaces the one in base.
+func mergePodConfig(base map[string]interface{}, override map[string]interface{}) map[string]interface{} {
+	merged := mergeConfigMaps(base, override)
+
+	baseDataspaces, baseOk := base["dataspaces"].([]interface{})
+	overrideDataspaces, overrideOk := override["dataspaces"].([]interface{})
+	if baseOk && overrideOk {
+		merged["dataspaces"] = mergeDataspaces(baseDataspaces, overrideDataspaces)
+	}
+
+	return merged
+}
+
+func mergeConfigMaps(base map[string]interface{}, override map[string]interface{}) map[string]interface{} {
+	merged := make(map[string]interface{}, len(base))
+	for key, value := range base {
+		merged[key] = value
+	}
+
+	for key, overrideValue := range override {
+		baseMap, baseIsMap := toStringMap(merged[key])
+		overrideMap, overrideIsMap := toStringMap(overrideValue)
+		if baseIsMap && overrideIsMap {
+			merged[key] = mergeConfigMaps(baseMap, overrideMap)
+			continue
+		}
+		merged[key] = overrideValue
+	}
+
+	return merged
+}
+
+func mergeDataspaces(base []interface{}, override []interface{}) []interface{} {
+	merged := make([]interface{}, len(base))
+	copy(merged, base)
+
+	for _, overrideDataspace := range override {
+		overrideMap, ok := toStringMap(overrideDataspace)
+		if !ok {
+			merged = append(merged, overrideDataspace)
+			continue
+		}
+
+		matched := false
+		for i, baseDataspace := range merged {
+			baseMap, ok := toStringMap(baseDataspace)
+			if ok && fmt.Sprint(baseMap["from"]) == fmt.Sprint(overrideMap["from"]) && fmt.Sprint(baseMap["name"]) == fmt.Sprint(overrideMap["name"]) {
+				merged[i] = mergeConfigMaps(baseMap, overrideMap)
+				matched = true
+				break
+			}
+		}
+
+		if !matched {
+			merged = append(merged, overrideMap)
+		}
+	}
+
+	return merged
+}
+
+func toStringMap(value interface{}) (map[string]interface{}, bool) {
+	switch m := value.(type) {
+	case map[string]interface{}:
+		return m, true
+	case map[interface{}]interface{}:
+		stringMap := make(map[string]interface{}, len(m))
+		for k, v := range m {
+			stringMap[fmt.Sprint(k)] = v
+		}
+		return stringMap, true
+	}
+
+	return nil, false
+}
+
+// Decodes the pod config strictly, returning an error for any key or param the pod spec does not define
+func validatePodSpec(v *viper.Viper) error {
+	var podSpec *spec.PodSpec
+	err := v.UnmarshalExact(&podSpec)
+	if err != nil {
+		return err
+	}
+
+	if podSpec == nil {
+		return nil
+	}
+
+	var unknownParams []string
+	for param := range podSpec.Params {
+		if !isKnownParam(param) {
+			unknownParams = append(unknownParams, param)
+		}
+	}
+
+	if len(unknownParams) > 0 {
+		sort.Strings(unknownParams)
+		return fmt.Errorf("unknown params: %s (expected one of %s)", strings.Join(unknownParams, ", "), strings.Join(knownParams, ", "))
+	}
+
+	return nil
+}
+
+func isKnownParam(param string) bool {
+	for _, knownParam := range knownParams {
+		if param == knownParam {
+			return true
+		}
+	}
+	return false
+}
+
+func unmarshalPod(podPath string) (*Pod, error) {
+	v, err := readPodConfig(podPath)
 	if err != nil {
 		return nil, err
 	}
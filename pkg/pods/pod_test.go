@@ -11,6 +11,7 @@ import (
 	"github.com/spiceai/data-components-contrib/dataprocessors"
 	"github.com/spiceai/data-components-contrib/dataprocessors/csv"
 	"github.com/spiceai/spiceai/pkg/interpretations"
+	"github.com/spiceai/spiceai/pkg/util"
 	"github.com/stretchr/testify/assert"
 )
 
@@ -39,6 +40,19 @@ func TestPod(t *testing.T) {
 	}
 }
 
+func TestPodOverride(t *testing.T) {
+	manifestPath := "../../test/assets/pods/overrides/trader.yaml"
+
+	pod, err := LoadPodFromManifest(manifestPath)
+	if err != nil {
+		t.Error(err)
+		return
+	}
+
+	t.Run("LoadPodFromManifest() - override manifest is merged", testPodOverrideMergedFunc(pod))
+	t.Run("OverrideManifestPath()/BaseManifestPath()/IsOverrideManifest()", testOverrideManifestPathsFunc())
+}
+
 // Tests base properties
 func testBasePropertiesFunc(pod *Pod) func(*testing.T) {
 	return func(t *testing.T) {
@@ -355,3 +369,47 @@ func testGetInterpretationsFunc(pod *Pod) func(*testing.T) {
 		assert.Equal(t, inRangeInterpretations, pod.GetInterpretations(startRange, endRange))
 	}
 }
+
+// Tests an override manifest is deep-merged into the pod manifest
+func testPodOverrideMergedFunc(pod *Pod) func(*testing.T) {
+	return func(t *testing.T) {
+		// Params set in the override replace the manifest's, others are kept
+		assert.Equal(t, "24h0m0s", pod.Period().String(), "invalid pod.Period()")
+		assert.Equal(t, "17m0s", pod.Interval().String(), "invalid pod.Interval()")
+		assert.Equal(t, "17s", pod.Granularity().String(), "invalid pod.Granularity()")
+
+		dataspaces := pod.DataSources()
+		if !assert.Equal(t, 3, len(dataspaces)) {
+			return
+		}
+
+		assert.Equal(t, "local/portfolio", dataspaces[0].Name())
+		assert.Equal(t, 1, len(dataspaces[0].Fields()))
+
+		// Dataspaces with the same from and name are merged
+		assert.Equal(t, "coinbase/btcusd", dataspaces[1].Name())
+		assert.Equal(t, "file", dataspaces[1].Data.Connector.Name)
+		assert.Equal(t, "../../test/assets/data/csv/trader.csv", dataspaces[1].Data.Connector.Params["path"])
+		assert.Equal(t, "csv", dataspaces[1].Data.Processor.Name)
+		assert.Equal(t, []string{"coinbase.btcusd.close", "local.orders.count", "local.portfolio.usd_balance"}, pod.FieldNames())
+
+		// New dataspaces are appended
+		assert.Equal(t, "local/orders", dataspaces[2].Name())
+
+		manifestHash, err := util.ComputeFileHash(pod.ManifestPath())
+		assert.NoError(t, err)
+		assert.NotEqual(t, manifestHash, pod.Hash(), "override manifest must change pod.Hash()")
+	}
+}
+
+// Tests override manifest path helpers
+func testOverrideManifestPathsFunc() func(*testing.T) {
+	return func(t *testing.T) {
+		assert.Equal(t, "spicepods/trader.override.yaml", OverrideManifestPath("spicepods/trader.yaml"))
+		assert.Equal(t, "spicepods/trader.override.yml", OverrideManifestPath("spicepods/trader.yml"))
+		assert.Equal(t, "spicepods/trader.yaml", BaseManifestPath("spicepods/trader.override.yaml"))
+		assert.True(t, IsOverrideManifest("spicepods/trader.override.yaml"))
+		assert.False(t, IsOverrideManifest("spicepods/trader.yaml"))
+		assert.False(t, IsOverrideManifest("spicepods/override.yaml"))
+	}
+}
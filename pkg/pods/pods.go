@@ -4,9 +4,12 @@ import (
 	"fmt"
 	"io/ioutil"
 	"log"
+	"os"
 	"path/filepath"
+	"strings"
 
 	"github.com/logrusorgru/aurora"
+	"github.com/spiceai/spiceai/pkg/constants"
 	"github.com/spiceai/spiceai/pkg/context"
 	"github.com/spiceai/spiceai/pkg/util"
 )
@@ -68,6 +71,9 @@ func FindFirstManifestPath() string {
 
 	for _, file := range files {
 		extension := filepath.Ext(file.Name())
+		if IsOverrideManifest(file.Name()) {
+			continue
+		}
 		if extension == ".yml" || extension == ".yaml" {
 			return filepath.Join(podsPath, file.Name())
 		}
@@ -83,6 +89,16 @@ func LoadPodFromManifest(manifestPath string) (*Pod, error) {
 		return nil, err
 	}
 
+	overridePath := OverrideManifestPath(manifestPath)
+	if _, err := os.Stat(overridePath); err == nil {
+		overrideHash, err := util.ComputeFileHash(overridePath)
+		if err != nil {
+			log.Printf("Error: Failed to compute hash for override manifest '%s: %s\n", overridePath, err)
+			return nil, err
+		}
+		manifestHash += overrideHash
+	}
+
 	pod, err := loadPod(manifestPath, manifestHash)
 	if err != nil {
 		log.Printf("Error: Failed to load manifest '%s': %s\n", manifestPath, err)
@@ -91,3 +107,43 @@ func LoadPodFromManifest(manifestPath string) (*Pod, error) {
 
 	return pod, nil
 }
+
+// ValidateManifest loads the pod manifest at manifestPath and checks it only uses keys and params
+// defined by the pod spec and is valid for training
+func ValidateManifest(manifestPath string) error {
+	v, err := readPodConfig(manifestPath)
+	if err != nil {
+		return err
+	}
+
+	err = validatePodSpec(v)
+	if err != nil {
+		return err
+	}
+
+	pod, err := loadPod(manifestPath, "")
+	if err != nil {
+		return err
+	}
+
+	return pod.ValidateForTraining()
+}
+
+// OverrideManifestPath returns the path of the override manifest that is deep-merged into
+// the pod manifest at manifestPath, e.g. trader.override.yaml for trader.yaml
+func OverrideManifestPath(manifestPath string) string {
+	ext := filepath.Ext(manifestPath)
+	return strings.TrimSuffix(manifestPath, ext) + constants.SpicePodOverrideSuffix + ext
+}
+
+// BaseManifestPath returns the path of the pod manifest an override manifest applies to
+func BaseManifestPath(overridePath string) string {
+	ext := filepath.Ext(overridePath)
+	return strings.TrimSuffix(strings.TrimSuffix(overridePath, ext), constants.SpicePodOverrideSuffix) + ext
+}
+
+// IsOverrideManifest returns whether manifestPath is an override manifest rather than a pod manifest
+func IsOverrideManifest(manifestPath string) bool {
+	ext := filepath.Ext(manifestPath)
+	return strings.HasSuffix(strings.TrimSuffix(manifestPath, ext), constants.SpicePodOverrideSuffix)
+}
@@ -6,6 +6,7 @@ import (
 	"os"
 	"path/filepath"
 	"sync"
+	"time"
 
 	"github.com/logrusorgru/aurora"
 	"github.com/spf13/viper"
@@ -21,9 +22,15 @@ import (
 	"go.uber.org/zap"
 )
 
+type httpServer interface {
+	Start() error
+	Shutdown(gracePeriod time.Duration) error
+}
+
 type SpiceRuntime struct {
-	config *config.SpiceConfiguration
-	viper  *viper.Viper
+	config     *config.SpiceConfiguration
+	viper      *viper.Viper
+	httpServer httpServer
 }
 
 var (
@@ -67,7 +74,8 @@ func SingleRun(manifestPath string) error {
 		return err
 	}
 
-	err = spice_http.NewServer(runtime.config.HttpPort).Start()
+	runtime.httpServer = spice_http.NewServer(runtime.config.HttpPort, runtime.config.HttpCompression)
+	err = runtime.httpServer.Start()
 	if err != nil {
 		return err
 	}
@@ -108,7 +116,8 @@ func Run() error {
 		return err
 	}
 
-	err = spice_http.NewServer(runtime.config.HttpPort).Start()
+	runtime.httpServer = spice_http.NewServer(runtime.config.HttpPort, runtime.config.HttpCompression)
+	err = runtime.httpServer.Start()
 	if err != nil {
 		return err
 	}
@@ -137,6 +146,57 @@ func Run() error {
 	return nil
 }
 
+type ValidationResult struct {
+	ManifestPath string
+	Err          error
+}
+
+// Validate loads and validates the pod manifest at manifestPath, or every pod manifest
+// in the pods directory if manifestPath is empty, without starting any servers.
+// Each manifest's result is returned; an error is only returned if no manifests could be found.
+func Validate(manifestPath string) ([]ValidationResult, error) {
+	manifestPaths := []string{manifestPath}
+	if manifestPath == "" {
+		var err error
+		manifestPaths, err = findManifestPaths()
+		if err != nil {
+			return nil, err
+		}
+		if len(manifestPaths) == 0 {
+			return nil, fmt.Errorf("no pod manifests found in %s", context.CurrentContext().PodsDir())
+		}
+	}
+
+	results := make([]ValidationResult, 0, len(manifestPaths))
+	for _, path := range manifestPaths {
+		results = append(results, ValidationResult{
+			ManifestPath: path,
+			Err:          pods.ValidateManifest(path),
+		})
+	}
+
+	return results, nil
+}
+
+func findManifestPaths() ([]string, error) {
+	podsManifestDir := context.CurrentContext().PodsDir()
+	files, err := os.ReadDir(podsManifestDir)
+	if err != nil {
+		return nil, err
+	}
+
+	var manifestPaths []string
+	for _, f := range files {
+		extension := filepath.Ext(f.Name())
+		if f.IsDir() || (extension != ".yml" && extension != ".yaml") || pods.IsOverrideManifest(f.Name()) {
+			continue
+		}
+		manifestPaths = append(manifestPaths, filepath.Join(podsManifestDir, f.Name()))
+	}
+
+	return manifestPaths, nil
+}
+
 func (r *SpiceRuntime) scanForPods() error {
 	_, err := os.Stat(context.CurrentContext().AppDir())
 	if err != nil {
@@ -163,7 +223,7 @@ func (r *SpiceRuntime) scanForPods() error {
 	}
 
 	for _, f := range files {
-		if f.IsDir() {
+		if f.IsDir() || pods.IsOverrideManifest(f.Name()) {
 			continue
 		}
 
@@ -215,6 +275,17 @@ func initializePod(manifestPath string) (*pods.Pod, error) {
 func Shutdown() {
 	log.Println("Shutting down...")
 
+	if runtime.httpServer != nil {
+		gracePeriod := runtime.config.GetShutdownGracePeriod()
+		log.Printf("Draining in-flight requests (up to %s) ...\n", gracePeriod)
+		err := runtime.httpServer.Shutdown(gracePeriod)
+		if err != nil {
+			log.Printf("error draining requests: %s\n", err.Error())
+		} else {
+			log.Println("Drained in-flight requests")
+		}
+	}
+
 	wg := new(sync.WaitGroup)
 	wg.Add(1)
 
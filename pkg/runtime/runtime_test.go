@@ -0,0 +1,141 @@
+package runtime
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/spiceai/spiceai/pkg/context"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestValidate(t *testing.T) {
+	t.Run("Validate() - valid manifest", testValidateFunc("../../test/assets/pods/manifests/trader.yaml", ""))
+	t.Run("Validate() - missing manifest", testValidateFunc("../../test/assets/pods/manifests/does-not-exist.yaml", "no such file or directory"))
+	t.Run("Validate() - manifest invalid for training", testValidateFunc("../../test/assets/pods/manifests/trader-invalid.yaml", "interval must be less than or equal to period"))
+	t.Run("Validate() - manifest with unknown key", testValidateFunc("../../test/assets/pods/manifests/trader-unknown-keys.yaml", "invalid keys: dataspace"))
+	t.Run("Validate() - manifest with unknown param", testValidateFunc("../../test/assets/pods/manifests/trader-unknown-params.yaml", "unknown params: granularty"))
+}
+
+func TestValidatePodsDirectory(t *testing.T) {
+	origContext := context.CurrentContext()
+	t.Cleanup(func() { context.SetContext(origContext) })
+
+	t.Run("Validate() - no manifests in pods directory", testValidateEmptyPodsDirectoryFunc())
+	t.Run("Validate() - all manifests in pods directory", testValidatePodsDirectoryFunc())
+}
+
+func testValidateFunc(manifestPath string, expectedError string) func(t *testing.T) {
+	return func(t *testing.T) {
+		results, err := Validate(manifestPath)
+		if !assert.NoError(t, err) || !assert.Len(t, results, 1) {
+			return
+		}
+
+		assert.Equal(t, manifestPath, results[0].ManifestPath)
+		if expectedError == "" {
+			assert.NoError(t, results[0].Err)
+		} else if assert.Error(t, results[0].Err) {
+			assert.Contains(t, results[0].Err.Error(), expectedError)
+		}
+	}
+}
+
+func testValidateEmptyPodsDirectoryFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		podsDir := setTestPodsDirectory(t)
+
+		_, err := Validate("")
+		if assert.Error(t, err) {
+			assert.Equal(t, "no pod manifests found in "+podsDir, err.Error())
+		}
+	}
+}
+
+func testValidatePodsDirectoryFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		invalidManifest, err := os.ReadFile("../../test/assets/pods/manifests/trader-invalid.yaml")
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		podsDir := setTestPodsDirectory(t)
+
+		validManifest := []byte(`name: valid
+dataspaces:
+  - from: local
+    name: portfolio
+    fields:
+      - name: usd_balance
+    actions:
+      buy: usd_balance -= 1
+actions:
+  - name: buy
+    do:
+      name: local.portfolio.buy
+training:
+  rewards:
+    - reward: buy
+      with: reward = 1
+`)
+		writeTestFile(t, filepath.Join(podsDir, "valid.yaml"), validManifest)
+		writeTestFile(t, filepath.Join(podsDir, "invalid.yml"), invalidManifest)
+		// Non-YAML files, override manifests and directories are not pod manifests
+		writeTestFile(t, filepath.Join(podsDir, "valid.override.yaml"), []byte("params:\n  period: 1h\n"))
+		writeTestFile(t, filepath.Join(podsDir, "notes.txt"), invalidManifest)
+		err = os.Mkdir(filepath.Join(podsDir, "nested.yaml"), 0766)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		results, err := Validate("")
+		if !assert.NoError(t, err) || !assert.Len(t, results, 2) {
+			return
+		}
+
+		assert.Equal(t, filepath.Join(podsDir, "invalid.yml"), results[0].ManifestPath)
+		assert.Error(t, results[0].Err)
+		assert.Equal(t, filepath.Join(podsDir, "valid.yaml"), results[1].ManifestPath)
+		assert.NoError(t, results[1].Err)
+	}
+}
+
+// Points the current context at an empty pods directory under a temporary app directory
+func setTestPodsDirectory(t *testing.T) string {
+	appDir := t.TempDir()
+
+	origDir, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	err = os.Chdir(appDir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { _ = os.Chdir(origDir) })
+
+	rtcontext, err := context.NewContext("metal")
+	if err != nil {
+		t.Fatal(err)
+	}
+	err = rtcontext.Init()
+	if err != nil {
+		t.Fatal(err)
+	}
+	context.SetContext(rtcontext)
+
+	podsDir := rtcontext.PodsDir()
+	err = os.Mkdir(podsDir, 0766)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	return podsDir
+}
+
+func writeTestFile(t *testing.T, path string, content []byte) {
+	err := os.WriteFile(path, content, 0666)
+	if err != nil {
+		t.Fatal(err)
+	}
+}
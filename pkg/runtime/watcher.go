@@ -64,6 +64,15 @@ func processNotifyEvent(event fsnotify.Event) error {
 		return nil
 	}
 
+	if pods.IsOverrideManifest(manifestPath) {
+		// Any change to an override manifest reloads the pod it applies to
+		manifestPath = pods.BaseManifestPath(manifestPath)
+		if _, err := os.Stat(manifestPath); err != nil {
+			return nil
+		}
+		event.Op = fsnotify.Write
+	}
+
 	switch event.Op {
 	case fsnotify.Create:
 		pod, err := pods.LoadPodFromManifest(manifestPath)
@@ -4,9 +4,11 @@ import (
 	"archive/zip"
 	"bytes"
 	"errors"
+	"fmt"
 	"io"
 	"os"
 	"path/filepath"
+	"regexp"
 	"strings"
 )
 
@@ -128,7 +130,11 @@ func ReplaceEnvVariablesFromPath(filePath string, envVarPrefix string) ([]byte,
 		return nil, err
 	}
 
-	contentString := string(content)
+	contentString, err := ReplaceEnvReferences(string(content))
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", filePath, err)
+	}
+
 	for _, envVarValPair := range os.Environ() {
 		if strings.HasPrefix(envVarValPair, envVarPrefix) {
 			envVar := strings.Split(envVarValPair, "=")[0]
@@ -139,6 +145,94 @@ func ReplaceEnvVariablesFromPath(filePath string, envVarPrefix string) ([]byte,
 	return []byte(contentString), nil
 }
 
+var envReferenceRegex = regexp.MustCompile(`\$\{env:([A-Za-z_][A-Za-z0-9_]*)(:-([^}]*))?\}`)
+
+// Replaces ${env:VAR} references in YAML content with the value of the VAR environment variable.
+// ${env:VAR:-default} falls back to default when VAR is not set.
+// References to unset variables without a default are an error.
+// References inside YAML # comments are left as-is.
+func ReplaceEnvReferences(content string) (string, error) {
+	var missing []string
+	seenMissing := make(map[string]bool)
+
+	lines := strings.Split(content, "\n")
+	for i, line := range lines {
+		commentStart := yamlCommentStart(line)
+		lines[i] = envReferenceRegex.ReplaceAllStringFunc(line[:commentStart], func(reference string) string {
+			match := envReferenceRegex.FindStringSubmatch(reference)
+			if value, ok := os.LookupEnv(match[1]); ok {
+				return value
+			}
+			if match[2] != "" {
+				return match[3]
+			}
+			if !seenMissing[match[1]] {
+				seenMissing[match[1]] = true
+				missing = append(missing, match[1])
+			}
+			return reference
+		}) + line[commentStart:]
+	}
+
+	if len(missing) > 0 {
+		return "", fmt.Errorf("environment variables not set: %s", strings.Join(missing, ", "))
+	}
+
+	return strings.Join(lines, "\n"), nil
+}
+
+// Returns the index where a YAML comment starts in line, or len(line) if there is none.
+// A comment starts at a # that begins the line or follows whitespace, outside of a quoted value.
+func yamlCommentStart(line string) int {
+	var quote byte
+	for i := 0; i < len(line); i++ {
+		c := line[i]
+		if quote != 0 {
+			switch {
+			case quote == '"' && c == '\\':
+				// Skip the escaped character
+				i++
+			case quote == '\'' && c == '\'' && i+1 < len(line) && line[i+1] == '\'':
+				// '' is an escaped single quote
+				i++
+			case c == quote:
+				quote = 0
+			}
+			continue
+		}
+
+		switch {
+		case (c == '"' || c == '\'') && startsYamlValue(line, i):
+			quote = c
+		case c == '#' && (i == 0 || line[i-1] == ' ' || line[i-1] == '\t'):
+			return i
+		}
+	}
+	return len(line)
+}
+
+// Returns whether the character at i begins a YAML value: it is the first character on the line,
+// or follows ": ", "- ", "[", "{" or ",". Quotes anywhere else are part of a plain value.
+func startsYamlValue(line string, i int) bool {
+	j := i - 1
+	for j >= 0 && (line[j] == ' ' || line[j] == '\t') {
+		j--
+	}
+	if j < 0 {
+		return true
+	}
+
+	switch line[j] {
+	case '[', '{', ',':
+		return true
+	case ':', '-':
+		// Must be followed by whitespace to be an indicator
+		return j < i-1
+	}
+
+	return false
+}
+
 func MakeFileExecutable(filepath string) error {
 	return os.Chmod(filepath, 0777)
 }
@@ -0,0 +1,63 @@
+package util
+
+import (
+	"os"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestFile(t *testing.T) {
+	t.Run("ReplaceEnvReferences()", testReplaceEnvReferencesFunc())
+}
+
+// Tests "ReplaceEnvReferences()"
+func testReplaceEnvReferencesFunc() func(*testing.T) {
+	return func(t *testing.T) {
+		unsetEnvVar := "SPICE_TEST_ENV_REFERENCE_UNSET"
+		if _, ok := os.LookupEnv(unsetEnvVar); ok {
+			t.Errorf("%s must not be set during tests", unsetEnvVar)
+			return
+		}
+
+		t.Setenv("SPICE_TEST_ENV_REFERENCE", "replaced")
+
+		actual, err := ReplaceEnvReferences("value: ${env:SPICE_TEST_ENV_REFERENCE}")
+		assert.NoError(t, err, "ReplaceEnvReferences() failed")
+		assert.Equal(t, "value: replaced", actual, "ReplaceEnvReferences() was incorrect")
+
+		actual, err = ReplaceEnvReferences("value: ${env:SPICE_TEST_ENV_REFERENCE_UNSET:-default}")
+		assert.NoError(t, err, "ReplaceEnvReferences() failed")
+		assert.Equal(t, "value: default", actual, "ReplaceEnvReferences() did not use default")
+
+		actual, err = ReplaceEnvReferences("value: ${env:SPICE_TEST_ENV_REFERENCE}-${env:SPICE_TEST_ENV_REFERENCE_UNSET:-default}")
+		assert.NoError(t, err, "ReplaceEnvReferences() failed")
+		assert.Equal(t, "value: replaced-default", actual, "ReplaceEnvReferences() was incorrect with multiple references")
+
+		actual, err = ReplaceEnvReferences("value: '${env:SPICE_TEST_ENV_REFERENCE_UNSET:-}'")
+		assert.NoError(t, err, "ReplaceEnvReferences() failed")
+		assert.Equal(t, "value: ''", actual, "ReplaceEnvReferences() did not use empty default")
+
+		content := "# token: ${env:SPICE_TEST_ENV_REFERENCE_UNSET}\nvalue: '#${env:SPICE_TEST_ENV_REFERENCE}' # ${env:SPICE_TEST_ENV_REFERENCE}"
+		actual, err = ReplaceEnvReferences(content)
+		assert.NoError(t, err, "ReplaceEnvReferences() failed on references in comments")
+		assert.Equal(t, "# token: ${env:SPICE_TEST_ENV_REFERENCE_UNSET}\nvalue: '#replaced' # ${env:SPICE_TEST_ENV_REFERENCE}", actual, "ReplaceEnvReferences() replaced references in comments")
+
+		// Apostrophes in plain values don't start a quoted string
+		content = "description: Spice's trader # token: ${env:SPICE_TEST_ENV_REFERENCE_UNSET}"
+		actual, err = ReplaceEnvReferences(content)
+		assert.NoError(t, err, "ReplaceEnvReferences() failed on apostrophe in plain value")
+		assert.Equal(t, content, actual, "ReplaceEnvReferences() replaced references in comments")
+
+		// Escaped quotes don't end a double-quoted string
+		content = `value: "say \"hi\" #${env:SPICE_TEST_ENV_REFERENCE}" # ${env:SPICE_TEST_ENV_REFERENCE_UNSET}`
+		actual, err = ReplaceEnvReferences(content)
+		assert.NoError(t, err, "ReplaceEnvReferences() failed on escaped quote in double-quoted value")
+		assert.Equal(t, `value: "say \"hi\" #replaced" # ${env:SPICE_TEST_ENV_REFERENCE_UNSET}`, actual, "ReplaceEnvReferences() was incorrect with escaped quotes")
+
+		_, err = ReplaceEnvReferences("a: ${env:SPICE_TEST_ENV_REFERENCE_UNSET}\nb: ${env:SPICE_TEST_ENV_REFERENCE_UNSET}")
+		if assert.Error(t, err, "ReplaceEnvReferences() did not return err") {
+			assert.Equal(t, "environment variables not set: SPICE_TEST_ENV_REFERENCE_UNSET", err.Error())
+		}
+	}
+}